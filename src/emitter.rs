@@ -0,0 +1,252 @@
+use crate::schema::{Schema, Union, UnionKind};
+use crate::templates;
+use apigen_plugin_utils::{types, Result};
+
+/// Consumes the resolved `Schema` IR and renders it into a target output format. The
+/// Handlebars-based TypeScript interfaces (`TsInterfaceEmitter`) are the default; a
+/// second emitter (see `zod_emitter`) can be added without touching parsing or the
+/// dependency-ordering pass.
+pub trait Emitter {
+    /// Output emitted once, before any schema, e.g. imports or branded type aliases.
+    fn preamble(&self) -> String {
+        String::new()
+    }
+
+    fn emit(&mut self, schema: &Schema) -> Result<String>;
+}
+
+pub struct TsInterfaceEmitter<'a> {
+    templates: templates::Templates<'a>,
+    emit_type_guards: bool,
+}
+
+impl<'a> TsInterfaceEmitter<'a> {
+    pub fn new(emit_type_guards: bool) -> Self {
+        TsInterfaceEmitter {
+            templates: templates::Templates::new(),
+            emit_type_guards,
+        }
+    }
+
+    fn union_body(u: &Union) -> String {
+        match u.kind {
+            UnionKind::Intersection => u.members.join(" & "),
+            UnionKind::Union => match &u.discriminator_property {
+                Some(property) => u
+                    .members
+                    .iter()
+                    .zip(&u.member_tags)
+                    .map(|(m, tag)| format!("({{ {property}: \"{tag}\" }} & {m})"))
+                    .collect::<Vec<_>>()
+                    .join(" | "),
+                None => u.members.join(" | "),
+            },
+        }
+    }
+
+    /// Builds the `isFoo`/`assertFoo` guard expression for a single struct field.
+    ///
+    /// `required` means different things for array and non-array fields, matching
+    /// `STRUCT_TEMPLATE`: for an array it's the *elements* that may be `undefined`
+    /// (`Array<T | undefined>`), while the array field itself is never optional; for
+    /// every other field it's the field key that may be `undefined` (`field?: T`).
+    fn field_guard_check(field: &types::StructField) -> String {
+        let accessor = format!("x.{}", field.name);
+        if field.is_array {
+            let element_check = match &field.type_ {
+                types::StructFieldType::Ref(name) => format!("is{name}(v)"),
+                types::StructFieldType::Value(ty) => Self::primitive_typeof_check("v", ty),
+            };
+            let element_check = if field.required {
+                element_check
+            } else {
+                format!("(v === undefined || {element_check})")
+            };
+            format!("Array.isArray({accessor}) && {accessor}.every((v: unknown) => {element_check})")
+        } else {
+            let base = match &field.type_ {
+                types::StructFieldType::Ref(name) => format!("is{name}({accessor})"),
+                types::StructFieldType::Value(ty) => Self::primitive_typeof_check(&accessor, ty),
+            };
+            if field.required {
+                base
+            } else {
+                format!("({accessor} === undefined || {base})")
+            }
+        }
+    }
+
+    fn primitive_typeof_check(accessor: &str, ty: &str) -> String {
+        match ty {
+            "String" | "Uuid" | "Email" => format!("typeof {accessor} === \"string\""),
+            "bool" => format!("typeof {accessor} === \"boolean\""),
+            "Uint8Array" => format!("{accessor} instanceof Uint8Array"),
+            "Blob" => format!("{accessor} instanceof Blob"),
+            "Date" => format!("{accessor} instanceof Date"),
+            _ => format!("typeof {accessor} === \"number\""),
+        }
+    }
+
+    fn build_struct_guard(s: &types::Struct) -> templates::StructGuard {
+        templates::StructGuard {
+            name: s.name.clone(),
+            fields: s
+                .fields
+                .iter()
+                .map(|f| templates::GuardField {
+                    name: f.name.clone(),
+                    check: Self::field_guard_check(f),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<'a> Emitter for TsInterfaceEmitter<'a> {
+    fn preamble(&self) -> String {
+        templates::Templates::branded_types_preamble().to_string()
+    }
+
+    fn emit(&mut self, schema: &Schema) -> Result<String> {
+        match schema {
+            Schema::Struct(s) => {
+                let mut out = self.templates.render_struct_template(s)?;
+                if self.emit_type_guards {
+                    out.push_str(
+                        &self
+                            .templates
+                            .render_struct_guard_template(&Self::build_struct_guard(s))?,
+                    );
+                }
+                Ok(out)
+            }
+            Schema::Enum(e) => {
+                let mut out = self.templates.render_enum_template(e)?;
+                if self.emit_type_guards {
+                    out.push_str(
+                        &self
+                            .templates
+                            .render_enum_guard_template(&templates::EnumGuard {
+                                name: e.name.clone(),
+                            })?,
+                    );
+                }
+                Ok(out)
+            }
+            Schema::Union(u) => self.templates.render_union_template(&templates::Union {
+                name: u.name.clone(),
+                description: u.description.clone(),
+                body: Self::union_body(u),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_guard_check_required_primitive_test() {
+        let field = types::StructField {
+            name: "id".to_string(),
+            description: None,
+            type_: types::StructFieldType::Value("String".to_string()),
+            required: true,
+            is_array: false,
+        };
+        assert_eq!(
+            TsInterfaceEmitter::field_guard_check(&field),
+            "typeof x.id === \"string\""
+        );
+    }
+
+    #[test]
+    fn field_guard_check_optional_primitive_test() {
+        let field = types::StructField {
+            name: "id".to_string(),
+            description: None,
+            type_: types::StructFieldType::Value("String".to_string()),
+            required: false,
+            is_array: false,
+        };
+        assert_eq!(
+            TsInterfaceEmitter::field_guard_check(&field),
+            "(x.id === undefined || typeof x.id === \"string\")"
+        );
+    }
+
+    #[test]
+    fn field_guard_check_ref_test() {
+        let field = types::StructField {
+            name: "owner".to_string(),
+            description: None,
+            type_: types::StructFieldType::Ref("User".to_string()),
+            required: true,
+            is_array: false,
+        };
+        assert_eq!(
+            TsInterfaceEmitter::field_guard_check(&field),
+            "isUser(x.owner)"
+        );
+    }
+
+    #[test]
+    fn field_guard_check_array_of_refs_test() {
+        let field = types::StructField {
+            name: "friends".to_string(),
+            description: None,
+            type_: types::StructFieldType::Ref("User".to_string()),
+            required: true,
+            is_array: true,
+        };
+        assert_eq!(
+            TsInterfaceEmitter::field_guard_check(&field),
+            "Array.isArray(x.friends) && x.friends.every((v: unknown) => isUser(v))"
+        );
+    }
+
+    #[test]
+    fn field_guard_check_array_of_optional_refs_test() {
+        let field = types::StructField {
+            name: "friends".to_string(),
+            description: None,
+            type_: types::StructFieldType::Ref("User".to_string()),
+            required: false,
+            is_array: true,
+        };
+        assert_eq!(
+            TsInterfaceEmitter::field_guard_check(&field),
+            "Array.isArray(x.friends) && x.friends.every((v: unknown) => (v === undefined || isUser(v)))"
+        );
+    }
+
+    #[test]
+    fn union_body_intersection_test() {
+        let u = Union {
+            name: "Combined".to_string(),
+            description: None,
+            kind: UnionKind::Intersection,
+            members: vec!["A".to_string(), "B".to_string()],
+            member_tags: vec!["a".to_string(), "b".to_string()],
+            discriminator_property: None,
+        };
+        assert_eq!(TsInterfaceEmitter::union_body(&u), "A & B");
+    }
+
+    #[test]
+    fn union_body_discriminated_test() {
+        let u = Union {
+            name: "Shape".to_string(),
+            description: None,
+            kind: UnionKind::Union,
+            members: vec!["Circle".to_string(), "Square".to_string()],
+            member_tags: vec!["circle".to_string(), "square".to_string()],
+            discriminator_property: Some("kind".to_string()),
+        };
+        assert_eq!(
+            TsInterfaceEmitter::union_body(&u),
+            "({ kind: \"circle\" } & Circle) | ({ kind: \"square\" } & Square)"
+        );
+    }
+}
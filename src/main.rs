@@ -1,19 +1,159 @@
+mod emitter;
+mod schema;
 mod templates;
+mod zod_emitter;
+
 use apigen_plugin_utils::{codegen::Codegen, error::Error, input, oas3_utils, types, Result};
 use convert_case::{Case, Casing};
+use emitter::Emitter;
 use oas3::spec::{ObjectOrReference, SchemaType};
 use serde_json::Number;
 
 struct CodegenImpl<'a> {
-    templates: templates::Templates<'a>,
+    emitter: Box<dyn Emitter + 'a>,
 }
 
 impl<'a> CodegenImpl<'a> {
     pub fn new() -> Self {
-        CodegenImpl {
-            templates: templates::Templates::new(),
+        let emit_type_guards = std::env::var_os("APIGEN_TS_EMIT_TYPE_GUARDS").is_some();
+        let emitter: Box<dyn Emitter> = match std::env::var("APIGEN_TS_EMITTER").as_deref() {
+            Ok("zod") => Box::new(zod_emitter::ZodEmitter),
+            _ => Box::new(emitter::TsInterfaceEmitter::new(emit_type_guards)),
+        };
+        CodegenImpl { emitter }
+    }
+
+    fn preamble(&self) -> String {
+        self.emitter.preamble()
+    }
+
+    /// Returns the referenced schema name for a `$ref` member and `None` for an inline
+    /// object; `build_union` synthesizes inline `allOf` members itself and rejects
+    /// inline `oneOf`/`anyOf` members.
+    fn union_member_name(member: &ObjectOrReference<oas3::Schema>) -> Option<String> {
+        match member {
+            ObjectOrReference::Ref { ref_path } => ref_path.rsplit('/').next().map(str::to_string),
+            ObjectOrReference::Object(_) => None,
         }
     }
+
+    /// Renders a synthesized struct as a standalone TS object type literal, using the
+    /// same required/array semantics as `STRUCT_TEMPLATE`.
+    fn inline_object_literal(s: &types::Struct) -> String {
+        let fields = s
+            .fields
+            .iter()
+            .map(|f| {
+                let ty = match &f.type_ {
+                    types::StructFieldType::Ref(name) => name.clone(),
+                    types::StructFieldType::Value(ty) => ty.clone(),
+                };
+                if f.is_array {
+                    let element = if f.required {
+                        ty
+                    } else {
+                        format!("{ty} | undefined")
+                    };
+                    format!("{}: Array<{}>", f.name, element)
+                } else {
+                    let optional = if f.required { "" } else { "?" };
+                    format!("{}{}: {}", f.name, optional, ty)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        format!("{{ {fields} }}")
+    }
+
+    fn build_union(&mut self, name: &str, obj: &oas3::Schema) -> Result<schema::Union> {
+        let (members, kind) = if !obj.all_of.is_empty() {
+            (&obj.all_of, schema::UnionKind::Intersection)
+        } else if !obj.one_of.is_empty() {
+            (&obj.one_of, schema::UnionKind::Union)
+        } else {
+            (&obj.any_of, schema::UnionKind::Union)
+        };
+
+        let mut member_names = Vec::new();
+        let mut member_tags = Vec::new();
+        for (i, m) in members.iter().enumerate() {
+            match Self::union_member_name(m) {
+                Some(raw_name) => {
+                    member_names.push(self.str_to_enum_variant(&raw_name));
+                    member_tags.push(raw_name);
+                }
+                // OpenAPI's "base ref + local additions" allOf idiom (`A & { extra: ... }`):
+                // synthesize the inline extension into its own intersection operand
+                // instead of rejecting the whole schema.
+                None if kind == schema::UnionKind::Intersection => {
+                    let synthetic_name = format!("{name}Extra{i}");
+                    let extra = Self::parse_struct(self, &synthetic_name, m)?;
+                    let literal = Self::inline_object_literal(&extra);
+                    member_tags.push(literal.clone());
+                    member_names.push(literal);
+                }
+                None => {
+                    return Err(Error::Codegen(format!(
+                        "{name}: inline oneOf/anyOf members are not supported, only $ref"
+                    )));
+                }
+            }
+        }
+
+        let discriminator_property = if !obj.one_of.is_empty() {
+            obj.discriminator.as_ref().map(|d| d.property_name.clone())
+        } else {
+            None
+        };
+
+        Ok(schema::Union {
+            name: name.to_string(),
+            description: obj.description.clone(),
+            kind,
+            members: member_names,
+            member_tags,
+            discriminator_property,
+        })
+    }
+
+    fn build_schema_ir(
+        &mut self,
+        schema: (&std::string::String, &ObjectOrReference<oas3::Schema>),
+    ) -> Result<schema::Schema> {
+        let obj = oas3_utils::ObjectOrReference::object_or_error(schema.1)?;
+        if !obj.all_of.is_empty() || !obj.any_of.is_empty() || !obj.one_of.is_empty() {
+            Ok(schema::Schema::Union(self.build_union(schema.0, obj)?))
+        } else if obj.enum_values.is_empty() {
+            Ok(schema::Schema::Struct(Self::parse_struct(
+                self, schema.0, schema.1,
+            )?))
+        } else {
+            Ok(schema::Schema::Enum(Self::parse_enum(self, schema)?))
+        }
+    }
+
+    /// The codegen driver: lowers every `components/schemas` entry to IR, runs the
+    /// dependency-ordering pass, then hands each node to the active `Emitter`.
+    fn generate_ordered(&mut self, spec: &oas3::Spec) -> Result<String> {
+        let schemas = spec
+            .components
+            .as_ref()
+            .map(|c| c.schemas.clone())
+            .unwrap_or_default();
+
+        let mut items = Vec::new();
+        for (name, reference) in schemas.iter() {
+            items.push(self.build_schema_ir((name, reference))?);
+        }
+
+        let ordered = schema::topo_sort(items);
+
+        let mut out = String::new();
+        for item in &ordered {
+            out.push_str(&self.emitter.emit(item)?);
+        }
+        Ok(out)
+    }
 }
 
 impl<'a> Codegen for CodegenImpl<'a> {
@@ -42,39 +182,55 @@ impl<'a> Codegen for CodegenImpl<'a> {
     fn map_oas3_to_output_type(
         &mut self,
         oas3_type: oas3::spec::SchemaType,
-        _: Option<&str>,
-        _: &Option<Number>,
+        format: Option<&str>,
+        min_value: &Option<Number>,
     ) -> Result<String> {
-        let default_number = Ok("number".to_string());
+        let is_non_negative = min_value
+            .as_ref()
+            .and_then(Number::as_f64)
+            .is_some_and(|min| min >= 0.0);
 
         match oas3_type {
-            SchemaType::String => Ok("String".to_string()),
-            SchemaType::Number => default_number,
-            SchemaType::Integer => default_number,
+            SchemaType::String => Ok(match format {
+                Some("date") | Some("date-time") => "Date".to_string(),
+                Some("byte") => "Uint8Array".to_string(),
+                Some("binary") => "Blob".to_string(),
+                Some("uuid") => "Uuid".to_string(),
+                Some("email") => "Email".to_string(),
+                _ => "String".to_string(),
+            }),
+            SchemaType::Number => Ok("number".to_string()),
+            SchemaType::Integer => Ok(match format {
+                Some("int64") => "Int64".to_string(),
+                _ if is_non_negative => "Uint".to_string(),
+                _ => "number".to_string(),
+            }),
             SchemaType::Boolean => Ok("bool".to_string()),
             SchemaType::Array => Ok("Array".to_string()),
             _ => Err(Error::Codegen(format!("Unsupported type: {:?}", oas3_type))),
         }
     }
 
+    /// The `Codegen` trait's required per-schema entry point. `generate_ordered` doesn't
+    /// call this directly — it needs every schema lowered to IR *before* topo-sorting,
+    /// while this returns one schema's final rendered output — but both go through the
+    /// same `build_schema_ir` + `Emitter::emit` steps, so the two paths can't drift.
     fn parse_struct_or_enum(
         &mut self,
         schema: (&std::string::String, &ObjectOrReference<oas3::Schema>),
     ) -> Result<String> {
-        let obj = oas3_utils::ObjectOrReference::object_or_error(schema.1)?;
-        if obj.enum_values.is_empty() {
-            let s = Self::parse_struct(self, schema.0, schema.1)?;
-            self.templates.render_struct_template(&s)
-        } else {
-            let e = Self::parse_enum(self, schema)?;
-            self.templates.render_enum_template(&e)
-        }
+        let ir = self.build_schema_ir(schema)?;
+        self.emitter.emit(&ir)
     }
 }
 
 fn run() -> Result<()> {
     let spec = input::read_and_parse()?;
-    CodegenImpl::new().generate(&spec)?;
+    let mut codegen = CodegenImpl::new();
+    let preamble = codegen.preamble();
+    let output = codegen.generate_ordered(&spec)?;
+    print!("{preamble}");
+    print!("{output}");
     Ok(())
 }
 
@@ -141,6 +297,159 @@ mod tests {
         );
     }
 
+    #[test]
+    fn map_oas3_to_output_type_int64_test() {
+        let schema_type = SchemaType::Integer;
+        let format = Some("int64");
+        let min_value = None;
+        assert_eq!(
+            CodegenImpl::new()
+                .map_oas3_to_output_type(schema_type, format, &min_value)
+                .unwrap(),
+            "Int64"
+        );
+    }
+
+    #[test]
+    fn map_oas3_to_output_type_non_negative_integer_test() {
+        let schema_type = SchemaType::Integer;
+        let format = None;
+        let min_value = Some(Number::from(0));
+        assert_eq!(
+            CodegenImpl::new()
+                .map_oas3_to_output_type(schema_type, format, &min_value)
+                .unwrap(),
+            "Uint"
+        );
+    }
+
+    #[test]
+    fn map_oas3_to_output_type_date_test() {
+        let schema_type = SchemaType::String;
+        let format = Some("date-time");
+        let min_value = None;
+        assert_eq!(
+            CodegenImpl::new()
+                .map_oas3_to_output_type(schema_type, format, &min_value)
+                .unwrap(),
+            "Date"
+        );
+    }
+
+    #[test]
+    fn map_oas3_to_output_type_byte_test() {
+        let schema_type = SchemaType::String;
+        let format = Some("byte");
+        let min_value = None;
+        assert_eq!(
+            CodegenImpl::new()
+                .map_oas3_to_output_type(schema_type, format, &min_value)
+                .unwrap(),
+            "Uint8Array"
+        );
+    }
+
+    #[test]
+    fn map_oas3_to_output_type_binary_test() {
+        let schema_type = SchemaType::String;
+        let format = Some("binary");
+        let min_value = None;
+        assert_eq!(
+            CodegenImpl::new()
+                .map_oas3_to_output_type(schema_type, format, &min_value)
+                .unwrap(),
+            "Blob"
+        );
+    }
+
+    #[test]
+    fn map_oas3_to_output_type_uuid_test() {
+        let schema_type = SchemaType::String;
+        let format = Some("uuid");
+        let min_value = None;
+        assert_eq!(
+            CodegenImpl::new()
+                .map_oas3_to_output_type(schema_type, format, &min_value)
+                .unwrap(),
+            "Uuid"
+        );
+    }
+
+    #[test]
+    fn map_oas3_to_output_type_email_test() {
+        let schema_type = SchemaType::String;
+        let format = Some("email");
+        let min_value = None;
+        assert_eq!(
+            CodegenImpl::new()
+                .map_oas3_to_output_type(schema_type, format, &min_value)
+                .unwrap(),
+            "Email"
+        );
+    }
+
+    #[test]
+    fn union_member_name_ref_test() {
+        let member = ObjectOrReference::Ref {
+            ref_path: "#/components/schemas/Cat".to_string(),
+        };
+        assert_eq!(
+            CodegenImpl::union_member_name(&member),
+            Some("Cat".to_string())
+        );
+    }
+
+    #[test]
+    fn union_member_name_inline_object_test() {
+        let member = ObjectOrReference::Object(oas3::Schema::default());
+        assert_eq!(CodegenImpl::union_member_name(&member), None);
+    }
+
+    #[test]
+    fn parse_struct_or_enum_renders_struct_test() {
+        let obj = oas3::Schema::default();
+        let name = "Point".to_string();
+        let schema = ObjectOrReference::Object(obj);
+
+        let out = CodegenImpl::new()
+            .parse_struct_or_enum((&name, &schema))
+            .unwrap();
+
+        assert!(out.contains("export interface Point"));
+    }
+
+    #[test]
+    fn parse_struct_or_enum_renders_enum_test() {
+        let mut obj = oas3::Schema::default();
+        obj.enum_values = vec![serde_json::Value::String("Red".to_string())];
+        let name = "Color".to_string();
+        let schema = ObjectOrReference::Object(obj);
+
+        let out = CodegenImpl::new()
+            .parse_struct_or_enum((&name, &schema))
+            .unwrap();
+
+        assert!(out.contains("export enum Color"));
+    }
+
+    #[test]
+    fn build_union_casts_ref_names_through_str_to_enum_variant_test() {
+        let mut obj = oas3::Schema::default();
+        obj.any_of = vec![
+            ObjectOrReference::Ref {
+                ref_path: "#/components/schemas/cat-breed".to_string(),
+            },
+            ObjectOrReference::Ref {
+                ref_path: "#/components/schemas/1dog".to_string(),
+            },
+        ];
+
+        let union = CodegenImpl::new().build_union("Pet", &obj).unwrap();
+
+        assert_eq!(union.members, vec!["CatBreed", "_1_Dog"]);
+        assert_eq!(union.member_tags, vec!["cat-breed", "1dog"]);
+    }
+
     #[test]
     fn str_to_enum_variant_test() {
         assert_eq!(CodegenImpl::new().str_to_enum_variant("test"), "Test");
@@ -0,0 +1,200 @@
+use apigen_plugin_utils::types;
+
+/// How a `oneOf`/`anyOf`/`allOf` composition combines its members.
+#[derive(PartialEq)]
+pub enum UnionKind {
+    Intersection,
+    Union,
+}
+
+pub struct Union {
+    pub name: String,
+    pub description: Option<String>,
+    pub kind: UnionKind,
+    /// Type references (or, for a synthesized inline `allOf` extension, an object type
+    /// literal) making up the union body.
+    pub members: Vec<String>,
+    /// The raw, un-cased wire value for each entry in `members`, in the same order.
+    /// Only meaningful for a discriminated `oneOf` — the discriminator tag is the
+    /// literal `components/schemas` key, never the case-normalized type name.
+    pub member_tags: Vec<String>,
+    pub discriminator_property: Option<String>,
+}
+
+/// A fully-resolved, backend-neutral schema node: refs, composition, and identifiers
+/// are already finalized by `CodegenImpl`, so every `Emitter` shares the same model.
+pub enum Schema {
+    Struct(types::Struct),
+    Enum(types::Enum),
+    Union(Union),
+}
+
+impl Schema {
+    pub fn name(&self) -> &str {
+        match self {
+            Schema::Struct(s) => &s.name,
+            Schema::Enum(e) => &e.name,
+            Schema::Union(u) => &u.name,
+        }
+    }
+
+    fn dependencies(&self) -> Vec<String> {
+        match self {
+            Schema::Struct(s) => s
+                .fields
+                .iter()
+                .filter_map(|f| match &f.type_ {
+                    types::StructFieldType::Ref(name) => Some(name.clone()),
+                    types::StructFieldType::Value(_) => None,
+                })
+                .collect(),
+            Schema::Union(u) => u.members.clone(),
+            Schema::Enum(_) => Vec::new(),
+        }
+    }
+}
+
+/// Topologically sorts `items` (Kahn's algorithm) so a struct or union referencing
+/// another schema is always declared after it. On a dependency cycle, whatever is
+/// left over is appended in its original spec order: TypeScript hoists type
+/// declarations, so a forward reference still compiles.
+///
+/// Scope cut: this does not de-duplicate structurally identical schemas (the original
+/// ask). Every entry here comes from a named `components/schemas` key, so two distinct,
+/// user-declared types that happen to share a field layout (e.g. `Dog` and `Cat`, both
+/// `{ name, age }`) are different types and must both be emitted — de-duping them would
+/// silently drop one and rewrite references to it. The only anonymous/inline schemas
+/// this crate produces are `build_union`'s synthesized `allOf` extension objects, and
+/// those are inlined as TS object-literal strings directly into the union body (see
+/// `CodegenImpl::inline_object_literal`), never emitted as their own `Schema` entry — so
+/// there is no anonymous-schema IR node here to de-duplicate against.
+pub fn topo_sort(items: Vec<Schema>) -> Vec<Schema> {
+    use std::collections::{HashMap, HashSet, VecDeque};
+
+    let original_order: Vec<String> = items.iter().map(|i| i.name().to_string()).collect();
+    let mut by_name: HashMap<String, Schema> = items
+        .into_iter()
+        .map(|i| (i.name().to_string(), i))
+        .collect();
+
+    let mut in_degree: HashMap<String, usize> = HashMap::new();
+    let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
+    for (name, item) in &by_name {
+        in_degree.entry(name.clone()).or_insert(0);
+        for dep in item.dependencies() {
+            if dep != *name && by_name.contains_key(&dep) {
+                *in_degree.entry(name.clone()).or_insert(0) += 1;
+                dependents.entry(dep).or_default().push(name.clone());
+            }
+        }
+    }
+
+    let mut ready: VecDeque<String> = original_order
+        .iter()
+        .filter(|n| in_degree.get(*n).copied().unwrap_or(0) == 0)
+        .cloned()
+        .collect();
+    let mut sorted = Vec::new();
+    let mut emitted: HashSet<String> = HashSet::new();
+
+    while let Some(name) = ready.pop_front() {
+        if !emitted.insert(name.clone()) {
+            continue;
+        }
+        sorted.push(name.clone());
+        if let Some(deps) = dependents.get(&name) {
+            for dependent in deps {
+                if let Some(count) = in_degree.get_mut(dependent) {
+                    *count -= 1;
+                    if *count == 0 {
+                        ready.push_back(dependent.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    for name in &original_order {
+        if !emitted.contains(name) {
+            sorted.push(name.clone());
+            emitted.insert(name.clone());
+        }
+    }
+
+    sorted
+        .into_iter()
+        .filter_map(|name| by_name.remove(&name))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn struct_with_fields(name: &str, fields: Vec<types::StructField>) -> Schema {
+        Schema::Struct(types::Struct {
+            name: name.to_string(),
+            description: None,
+            fields,
+        })
+    }
+
+    fn value_field(name: &str) -> types::StructField {
+        types::StructField {
+            name: name.to_string(),
+            description: None,
+            type_: types::StructFieldType::Value("String".to_string()),
+            required: true,
+            is_array: false,
+        }
+    }
+
+    fn ref_field(name: &str, target: &str) -> types::StructField {
+        types::StructField {
+            name: name.to_string(),
+            description: None,
+            type_: types::StructFieldType::Ref(target.to_string()),
+            required: true,
+            is_array: false,
+        }
+    }
+
+    #[test]
+    fn topo_sort_orders_dependencies_before_dependents_test() {
+        let items = vec![
+            struct_with_fields("Owner", vec![ref_field("pet", "Pet")]),
+            struct_with_fields("Pet", vec![value_field("name")]),
+        ];
+
+        let sorted = topo_sort(items);
+        let names: Vec<&str> = sorted.iter().map(Schema::name).collect();
+
+        assert_eq!(names, vec!["Pet", "Owner"]);
+    }
+
+    #[test]
+    fn topo_sort_falls_back_to_original_order_on_cycle_test() {
+        let items = vec![
+            struct_with_fields("A", vec![ref_field("b", "B")]),
+            struct_with_fields("B", vec![ref_field("a", "A")]),
+        ];
+
+        let sorted = topo_sort(items);
+        let names: Vec<&str> = sorted.iter().map(Schema::name).collect();
+
+        assert_eq!(names, vec!["A", "B"]);
+    }
+
+    #[test]
+    fn topo_sort_keeps_distinct_schemas_with_identical_field_shapes_test() {
+        let items = vec![
+            struct_with_fields("Dog", vec![value_field("name")]),
+            struct_with_fields("Cat", vec![value_field("name")]),
+        ];
+
+        let sorted = topo_sort(items);
+        let names: Vec<&str> = sorted.iter().map(Schema::name).collect();
+
+        assert_eq!(names, vec!["Dog", "Cat"]);
+    }
+}
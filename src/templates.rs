@@ -37,6 +37,68 @@ export interface {{name}} {
 }
 "#;
 
+const STRUCT_GUARD_TEMPLATE: &str = r#"
+export function is{{name}}(x: unknown): x is {{name}} {
+    return (
+        typeof x === "object" &&
+        x !== null{{#each fields}} &&
+        {{{this.check}}}{{/each}}
+    );
+}
+
+export function assert{{name}}(x: unknown): asserts x is {{name}} {
+    if (!is{{name}}(x)) {
+        throw new Error("Expected {{name}}");
+    }
+}
+"#;
+
+const ENUM_GUARD_TEMPLATE: &str = r#"
+export function is{{name}}(x: unknown): x is {{name}} {
+    return Object.values({{name}}).includes(x as {{name}});
+}
+"#;
+
+#[derive(serde::Serialize)]
+pub struct GuardField {
+    pub name: String,
+    pub check: String,
+}
+
+#[derive(serde::Serialize)]
+pub struct StructGuard {
+    pub name: String,
+    pub fields: Vec<GuardField>,
+}
+
+#[derive(serde::Serialize)]
+pub struct EnumGuard {
+    pub name: String,
+}
+
+const UNION_TEMPLATE: &str = r#"
+{{#if description}}
+/**
+ * {{description}}
+ */
+{{/if}}
+export type {{name}} = {{{body}}};
+"#;
+
+#[derive(serde::Serialize)]
+pub struct Union {
+    pub name: String,
+    pub description: Option<String>,
+    pub body: String,
+}
+
+const BRANDED_TYPES_PREAMBLE: &str = r#"
+export type Uuid = string & { readonly __brand: "Uuid" };
+export type Email = string & { readonly __brand: "Email" };
+export type Uint = number & { readonly __brand: "Uint" };
+export type Int64 = number & { readonly __brand: "Int64" };
+"#;
+
 pub struct Templates<'a> {
     handlebars: Handlebars<'a>,
 }
@@ -53,6 +115,18 @@ impl<'a> Templates<'a> {
             .register_template_string("struct", STRUCT_TEMPLATE)
             .expect("Failed to register template");
 
+        handlebars
+            .register_template_string("struct_guard", STRUCT_GUARD_TEMPLATE)
+            .expect("Failed to register template");
+
+        handlebars
+            .register_template_string("enum_guard", ENUM_GUARD_TEMPLATE)
+            .expect("Failed to register template");
+
+        handlebars
+            .register_template_string("union", UNION_TEMPLATE)
+            .expect("Failed to register template");
+
         Templates { handlebars }
     }
 
@@ -67,6 +141,30 @@ impl<'a> Templates<'a> {
             .render("struct", s)
             .map_err(|e: handlebars::RenderError| Error::Codegen(e.to_string()))
     }
+
+    /// The branded type aliases referenced by `map_oas3_to_output_type`, emitted once
+    /// at the top of the generated file.
+    pub fn branded_types_preamble() -> &'static str {
+        BRANDED_TYPES_PREAMBLE
+    }
+
+    pub fn render_struct_guard_template(&self, g: &StructGuard) -> Result<String> {
+        self.handlebars
+            .render("struct_guard", g)
+            .map_err(|e: handlebars::RenderError| Error::Codegen(e.to_string()))
+    }
+
+    pub fn render_enum_guard_template(&self, g: &EnumGuard) -> Result<String> {
+        self.handlebars
+            .render("enum_guard", g)
+            .map_err(|e: handlebars::RenderError| Error::Codegen(e.to_string()))
+    }
+
+    pub fn render_union_template(&self, u: &Union) -> Result<String> {
+        self.handlebars
+            .render("union", u)
+            .map_err(|e: handlebars::RenderError| Error::Codegen(e.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -138,6 +236,68 @@ export enum Color {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_branded_types_preamble_contains_aliases() {
+        let preamble = Templates::branded_types_preamble();
+        assert!(preamble.contains("type Uuid"));
+        assert!(preamble.contains("type Email"));
+        assert!(preamble.contains("type Uint"));
+        assert!(preamble.contains("type Int64"));
+    }
+
+    #[test]
+    fn test_render_struct_guard_template() {
+        let g = StructGuard {
+            name: "Point".to_string(),
+            fields: vec![
+                GuardField {
+                    name: "x".to_string(),
+                    check: "typeof x.x === \"number\"".to_string(),
+                },
+                GuardField {
+                    name: "y".to_string(),
+                    check: "typeof x.y === \"number\"".to_string(),
+                },
+            ],
+        };
+
+        let t = Templates::new();
+        let actual = t.render_struct_guard_template(&g).unwrap();
+        assert!(actual.contains("export function isPoint(x: unknown): x is Point {"));
+        assert!(actual.contains("typeof x.x === \"number\""));
+        assert!(actual.contains("export function assertPoint(x: unknown): asserts x is Point {"));
+    }
+
+    #[test]
+    fn test_render_enum_guard_template() {
+        let g = EnumGuard {
+            name: "Color".to_string(),
+        };
+
+        let t = Templates::new();
+        let actual = t.render_enum_guard_template(&g).unwrap();
+        assert!(actual.contains("export function isColor(x: unknown): x is Color {"));
+        assert!(actual.contains("Object.values(Color).includes(x as Color);"));
+    }
+
+    #[test]
+    fn test_render_union_template() {
+        let u = Union {
+            name: "Shape".to_string(),
+            description: None,
+            body: "Circle | Square".to_string(),
+        };
+
+        let expected = r#"
+export type Shape = Circle | Square;
+"#
+        .to_string();
+
+        let t = Templates::new();
+        let actual = t.render_union_template(&u).unwrap();
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_render_struct_template_simple() {
         let expected = r#"
@@ -0,0 +1,192 @@
+use crate::emitter::Emitter;
+use crate::schema::{Schema, Union, UnionKind};
+use apigen_plugin_utils::{types, Result};
+use convert_case::{Case, Casing};
+
+/// Renders each `Schema::Struct` as `z.object({...})` and each `Schema::Enum` as
+/// `z.enum([...])`, reusing the same resolved IR `TsInterfaceEmitter` consumes.
+pub struct ZodEmitter;
+
+impl ZodEmitter {
+    fn schema_ref(name: &str) -> String {
+        format!("{}Schema", name.to_case(Case::Camel))
+    }
+
+    fn field_type(field: &types::StructField) -> String {
+        let mut zod_type = match &field.type_ {
+            types::StructFieldType::Ref(name) => Self::schema_ref(name),
+            types::StructFieldType::Value(ty) => Self::primitive_type(ty),
+        };
+        if field.is_array {
+            zod_type = format!("z.array({zod_type})");
+        }
+        if !field.required {
+            zod_type = format!("{zod_type}.optional()");
+        }
+        zod_type
+    }
+
+    fn primitive_type(ty: &str) -> String {
+        match ty {
+            "String" | "Uuid" | "Email" => "z.string()".to_string(),
+            "number" | "Uint" | "Int64" => "z.number()".to_string(),
+            "bool" => "z.boolean()".to_string(),
+            "Date" => "z.date()".to_string(),
+            "Uint8Array" | "Blob" => "z.instanceof(Uint8Array)".to_string(),
+            _ => "z.unknown()".to_string(),
+        }
+    }
+
+    fn render_struct(s: &types::Struct) -> String {
+        let mut fields = String::new();
+        for field in &s.fields {
+            fields.push_str(&format!("  {}: {},\n", field.name, Self::field_type(field)));
+        }
+        format!(
+            "export const {} = z.object({{\n{}}});\n",
+            Self::schema_ref(&s.name),
+            fields
+        )
+    }
+
+    fn render_enum(e: &types::Enum) -> String {
+        let values: Vec<String> = e
+            .variants
+            .iter()
+            .map(|v| format!("\"{}\"", v.value.clone().unwrap_or_else(|| v.name.clone())))
+            .collect();
+        format!(
+            "export const {} = z.enum([{}]);\n",
+            Self::schema_ref(&e.name),
+            values.join(", ")
+        )
+    }
+
+    fn render_union(u: &Union) -> String {
+        let members = u
+            .members
+            .iter()
+            .map(|m| Self::schema_ref(m))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let combinator = match u.kind {
+            UnionKind::Intersection => "z.intersection",
+            UnionKind::Union => "z.union",
+        };
+        format!(
+            "export const {} = {}([{}]);\n",
+            Self::schema_ref(&u.name),
+            combinator,
+            members
+        )
+    }
+}
+
+impl Emitter for ZodEmitter {
+    fn preamble(&self) -> String {
+        "import { z } from \"zod\";\n".to_string()
+    }
+
+    fn emit(&mut self, schema: &Schema) -> Result<String> {
+        Ok(match schema {
+            Schema::Struct(s) => Self::render_struct(s),
+            Schema::Enum(e) => Self::render_enum(e),
+            Schema::Union(u) => Self::render_union(u),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_struct_test() {
+        let s = types::Struct {
+            name: "Point".to_string(),
+            description: None,
+            fields: vec![
+                types::StructField {
+                    name: "x".to_string(),
+                    description: None,
+                    type_: types::StructFieldType::Value("number".to_string()),
+                    required: true,
+                    is_array: false,
+                },
+                types::StructField {
+                    name: "owner".to_string(),
+                    description: None,
+                    type_: types::StructFieldType::Ref("User".to_string()),
+                    required: false,
+                    is_array: false,
+                },
+            ],
+        };
+
+        let expected = "export const pointSchema = z.object({\n  x: z.number(),\n  owner: userSchema.optional(),\n});\n";
+        assert_eq!(ZodEmitter::render_struct(&s), expected);
+    }
+
+    #[test]
+    fn render_enum_test() {
+        let e = types::Enum {
+            name: "Color".to_string(),
+            description: None,
+            variants: vec![
+                types::EnumVariant {
+                    name: "Red".to_string(),
+                    value: None,
+                },
+                types::EnumVariant {
+                    name: "Green".to_string(),
+                    value: None,
+                },
+            ],
+        };
+
+        assert_eq!(
+            ZodEmitter::render_enum(&e),
+            "export const colorSchema = z.enum([\"Red\", \"Green\"]);\n"
+        );
+    }
+
+    #[test]
+    fn render_enum_uses_wire_value_when_it_differs_from_name_test() {
+        let e = types::Enum {
+            name: "Color".to_string(),
+            description: None,
+            variants: vec![
+                types::EnumVariant {
+                    name: "Red".to_string(),
+                    value: Some("1".to_string()),
+                },
+                types::EnumVariant {
+                    name: "Green".to_string(),
+                    value: Some("2".to_string()),
+                },
+            ],
+        };
+
+        assert_eq!(
+            ZodEmitter::render_enum(&e),
+            "export const colorSchema = z.enum([\"1\", \"2\"]);\n"
+        );
+    }
+
+    #[test]
+    fn render_union_test() {
+        let u = Union {
+            name: "Shape".to_string(),
+            description: None,
+            kind: UnionKind::Union,
+            members: vec!["Circle".to_string(), "Square".to_string()],
+            member_tags: vec!["circle".to_string(), "square".to_string()],
+            discriminator_property: None,
+        };
+
+        assert_eq!(
+            ZodEmitter::render_union(&u),
+            "export const shapeSchema = z.union([circleSchema, squareSchema]);\n"
+        );
+    }
+}